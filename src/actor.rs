@@ -21,7 +21,10 @@
 use core::fmt::{Debug, Display};
 use std::hash::Hash;
 
+pub mod bls;
 pub mod ed25519;
+pub mod multi;
+pub mod purpose;
 
 use serde::Serialize;
 use signature::{Signature, Signer, Verifier};
@@ -48,3 +51,28 @@ pub trait SigningActor<A, S: Signature>: Signer<S> + Default + Display + Debug +
 pub trait Sig: Signature + Display + Clone + Debug + Eq + Ord + Hash + Serialize {}
 
 impl<T> Sig for T where T: Signature + Display + Debug + Clone + Eq + Ord + Hash + Serialize {}
+
+/// An optional capability for a signing actor that can consume a message in
+/// pieces instead of requiring the whole `&[u8]` up front, useful for large
+/// or streamed payloads (e.g. anti-entropy state). A backend that can only
+/// sign one-shot messages (like the bare `Signer<S>` trait) simply doesn't
+/// implement this.
+pub trait StreamingSigner<S: Signature> {
+    /// Feeds the next chunk of the message into the signer.
+    fn update(&mut self, chunk: &[u8]);
+
+    /// Consumes the accumulated message and produces a signature over it.
+    /// Must be identical to signing the concatenation of every `update`d
+    /// chunk in one call, so streamed and non-streamed signatures over the
+    /// same bytes are interchangeable.
+    fn finalize(self) -> Result<S, signature::Error>;
+}
+
+/// The verifying counterpart of [`StreamingSigner`].
+pub trait StreamingVerifier<S: Signature> {
+    /// Feeds the next chunk of the message into the verifier.
+    fn update(&mut self, chunk: &[u8]);
+
+    /// Consumes the accumulated message and checks it against `signature`.
+    fn finalize_and_verify(self, signature: &S) -> Result<(), signature::Error>;
+}
@@ -7,6 +7,9 @@ use std::convert::TryInto;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+pub mod blind;
+pub mod multipart;
+
 const CONTEXT: &[u8] = b"BRBEd25519DalekSignerPrehashedContext";
 
 #[derive(Clone, Copy, Serialize, Deserialize)]
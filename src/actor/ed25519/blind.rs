@@ -0,0 +1,198 @@
+//! Blinded actor identities, following the blind-keys capability in
+//! ed25519-compact: a per-group `seed` derives a blinding scalar that shifts
+//! a long-term keypair to an unlinkable one, while signatures made under the
+//! blinded key still verify against the blinded public key alone.
+//!
+//! Blinding here operates on the raw scalar/point rather than going through
+//! `ed25519_dalek::Keypair` (whose `ExpandedSecretKey` can't be rebuilt from
+//! an arbitrary scalar via its public API), so `BlindedActor`'s `Verifier`
+//! impl checks the Schnorr/EdDSA equation directly with `curve25519-dalek`
+//! instead of delegating to dalek's own `verify_strict`.
+
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use ed25519::{Digest, Sha512, Signature as DalekSignature};
+use serde::{Deserialize, Serialize};
+use signature::{Signer, Verifier};
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::{Sig, SigningActor};
+
+const BLIND_CONTEXT: &[u8] = b"BRBEd25519BlindedActorContext";
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct BlindedActor(CompressedEdwardsY);
+
+impl Verifier<Sig> for BlindedActor {
+    fn verify(&self, msg: &[u8], signature: &Sig) -> Result<(), signature::Error> {
+        let point = self
+            .0
+            .decompress()
+            .ok_or_else(signature::Error::new)?;
+        let bytes = signature.0.to_bytes();
+        let big_r = CompressedEdwardsY(bytes[..32].try_into().unwrap())
+            .decompress()
+            .ok_or_else(signature::Error::new)?;
+        let s = Scalar::from_canonical_bytes(bytes[32..].try_into().unwrap())
+            .ok_or_else(signature::Error::new)?;
+
+        let c = challenge(&big_r, &self.0, msg);
+        let expected = &s * &ED25519_BASEPOINT_TABLE;
+        if expected == big_r + c * point {
+            Ok(())
+        } else {
+            Err(signature::Error::new())
+        }
+    }
+}
+
+impl fmt::Display for BlindedActor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b:{}", hex::encode(&self.0.to_bytes()[..3]))
+    }
+}
+
+impl fmt::Debug for BlindedActor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Hash for BlindedActor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+impl Ord for BlindedActor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_bytes().cmp(&other.0.to_bytes())
+    }
+}
+
+impl PartialOrd for BlindedActor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BlindedActor {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BlindedActor {}
+
+pub struct BlindedSigningActor {
+    scalar: Scalar,
+    nonce_prefix: [u8; 32],
+    actor: BlindedActor,
+}
+
+impl Signer<Sig> for BlindedSigningActor {
+    fn try_sign(&self, msg: &[u8]) -> Result<Sig, signature::Error> {
+        let r = nonce(&self.nonce_prefix, msg);
+        let big_r = &r * &ED25519_BASEPOINT_TABLE;
+        let c = challenge(&big_r, &self.actor.0, msg);
+        let s = r + c * self.scalar;
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(big_r.compress().as_bytes());
+        bytes[32..].copy_from_slice(s.as_bytes());
+
+        Ok(Sig(DalekSignature::new(bytes)))
+    }
+}
+
+impl crate::SigningActor<BlindedActor, Sig> for BlindedSigningActor {
+    fn actor(&self) -> BlindedActor {
+        self.actor
+    }
+}
+
+fn blinding_scalar(seed: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(BLIND_CONTEXT);
+    hasher.update(b"scalar");
+    hasher.update(seed);
+    Scalar::from_hash(hasher)
+}
+
+fn blinding_nonce_prefix(base_prefix: &[u8], seed: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha512::new();
+    hasher.update(BLIND_CONTEXT);
+    hasher.update(b"nonce");
+    hasher.update(base_prefix);
+    hasher.update(seed);
+    let digest = hasher.finalize();
+    let mut prefix = [0u8; 32];
+    prefix.copy_from_slice(&digest[..32]);
+    prefix
+}
+
+fn nonce(prefix: &[u8; 32], msg: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(prefix);
+    hasher.update(msg);
+    Scalar::from_hash(hasher)
+}
+
+fn challenge(big_r: &EdwardsPoint, actor_point: &CompressedEdwardsY, msg: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(BLIND_CONTEXT);
+    hasher.update(b"challenge");
+    hasher.update(big_r.compress().as_bytes());
+    hasher.update(actor_point.as_bytes());
+    hasher.update(msg);
+    Scalar::from_hash(hasher)
+}
+
+impl super::Actor {
+    /// Derives the blinded public identity a group using `seed` would see
+    /// for this actor, without revealing the link to `self` to anyone who
+    /// doesn't also know `seed`.
+    pub fn blind(&self, seed: &[u8]) -> BlindedActor {
+        let point = self.0.as_bytes();
+        let base = CompressedEdwardsY(*point)
+            .decompress()
+            .expect("valid ed25519 public key");
+        let blinded = base + &blinding_scalar(seed) * &ED25519_BASEPOINT_TABLE;
+        BlindedActor(blinded.compress())
+    }
+}
+
+impl super::SigningActor {
+    /// Derives the blinded keypair this actor would sign as within a group
+    /// using `seed`. Signatures made with the result verify under
+    /// `self.actor().blind(seed)` but cannot be linked back to `self` without
+    /// `seed`.
+    pub fn blind(&self, seed: &[u8]) -> BlindedSigningActor {
+        let expanded = ed25519::ExpandedSecretKey::from(&self.0.secret);
+        let expanded_bytes = expanded.to_bytes();
+        let base_scalar = Scalar::from_bits(expanded_bytes[..32].try_into().unwrap());
+        let base_prefix = &expanded_bytes[32..64];
+
+        let scalar = base_scalar + blinding_scalar(seed);
+        let nonce_prefix = blinding_nonce_prefix(base_prefix, seed);
+        let actor = self.actor().blind(seed);
+
+        BlindedSigningActor {
+            scalar,
+            nonce_prefix,
+            actor,
+        }
+    }
+}
+
+/// Recovers a holder's own blinded keypair for `seed` from their long-term
+/// `SigningActor`, given they already know `seed` (e.g. it was published by
+/// the group). There is no way to unblind a signature or identity without
+/// knowing `seed`, which is the point.
+pub fn unblind(signing_actor: &SigningActor, seed: &[u8]) -> BlindedSigningActor {
+    signing_actor.blind(seed)
+}
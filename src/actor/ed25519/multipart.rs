@@ -0,0 +1,66 @@
+//! Incremental signing and verification, mirroring dryoc's multi-part sign
+//! API: callers `update` repeatedly and `finalize`/`finalize_and_verify`
+//! once the full message has been streamed through, rather than needing it
+//! buffered as one `&[u8]`. This wraps the same `Sha512` prehash and
+//! `CONTEXT` domain separation as the one-shot [`super::SigningActor`]'s
+//! `try_sign`, so a streamed signature and a one-shot signature over the
+//! same bytes are interchangeable.
+
+use ed25519::{Digest, Sha512};
+
+use super::{Actor, Sig, SigningActor, CONTEXT};
+use crate::actor::{StreamingSigner, StreamingVerifier};
+
+pub struct MultipartSigner<'a> {
+    signing_actor: &'a SigningActor,
+    hasher: Sha512,
+}
+
+impl<'a> MultipartSigner<'a> {
+    pub fn new(signing_actor: &'a SigningActor) -> Self {
+        Self {
+            signing_actor,
+            hasher: Sha512::new(),
+        }
+    }
+}
+
+impl<'a> StreamingSigner<Sig> for MultipartSigner<'a> {
+    fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    fn finalize(self) -> Result<Sig, signature::Error> {
+        let sig = self
+            .signing_actor
+            .0
+            .sign_prehashed(self.hasher, Some(CONTEXT))?;
+        Ok(Sig(sig))
+    }
+}
+
+pub struct MultipartVerifier<'a> {
+    actor: &'a Actor,
+    hasher: Sha512,
+}
+
+impl<'a> MultipartVerifier<'a> {
+    pub fn new(actor: &'a Actor) -> Self {
+        Self {
+            actor,
+            hasher: Sha512::new(),
+        }
+    }
+}
+
+impl<'a> StreamingVerifier<Sig> for MultipartVerifier<'a> {
+    fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    fn finalize_and_verify(self, signature: &Sig) -> Result<(), signature::Error> {
+        self.actor
+            .0
+            .verify_prehashed(self.hasher, Some(CONTEXT), &signature.0)
+    }
+}
@@ -0,0 +1,121 @@
+//! Purpose-scoped signatures.
+//!
+//! `Actor`/`SigningActor` sign and verify plain byte slices, which means a
+//! signature made for one kind of membership message could in principle be
+//! replayed as another (a join-request signature re-submitted as a
+//! leave-request, say). `Signed<T, P>` mixes a caller-chosen domain tag
+//! `P::TAG` into the signed bytes, so a signature only verifies against the
+//! `Purpose` it was made for.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use serde::Serialize;
+use signature::{Signature, Signer, Verifier};
+
+use crate::actor::{Actor, SigningActor};
+
+/// A domain/purpose tag mixed into the prehash of every `Signed<T, Self>`.
+/// Two purposes with different `TAG`s can never be mistaken for each other,
+/// even if the underlying value `T` serializes identically.
+pub trait Purpose {
+    const TAG: &'static [u8];
+}
+
+/// A join-request signature can never be replayed as a leave-request or vice
+/// versa, since each ships as a distinct marker type implementing `Purpose`.
+pub struct Join;
+impl Purpose for Join {
+    const TAG: &'static [u8] = b"brb_membership::purpose::join";
+}
+
+pub struct Leave;
+impl Purpose for Leave {
+    const TAG: &'static [u8] = b"brb_membership::purpose::leave";
+}
+
+pub struct AntiEntropyAck;
+impl Purpose for AntiEntropyAck {
+    const TAG: &'static [u8] = b"brb_membership::purpose::anti_entropy_ack";
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to encode the signed value")]
+    Encoding(#[from] bincode::Error),
+    #[error("signature verification failed")]
+    Verification(#[from] signature::Error),
+}
+
+/// A value of type `T` together with a signature that has been verified (or,
+/// on the signing side, produced) against the `Join`/`Leave`/... `Purpose`
+/// `P`. Construction always goes through `sign` or `verify`, so a `Signed<T,
+/// Join>` can never be handed to code expecting a `Signed<T, Leave>`.
+#[derive(Clone)]
+pub struct Signed<T, P: Purpose, A, S> {
+    value: T,
+    pub sig: S,
+    pub actor: A,
+    _purpose: PhantomData<P>,
+}
+
+impl<T, P: Purpose, A, S> Deref for Signed<T, P, A, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, P, A, S> fmt::Debug for Signed<T, P, A, S>
+where
+    T: fmt::Debug,
+    P: Purpose,
+    A: fmt::Debug,
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signed")
+            .field("value", &self.value)
+            .field("actor", &self.actor)
+            .field("sig", &self.sig)
+            .finish()
+    }
+}
+
+fn purpose_scoped_bytes<T: Serialize, P: Purpose>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut bytes = P::TAG.to_vec();
+    bytes.extend(bincode::serialize(value)?);
+    Ok(bytes)
+}
+
+impl<T: Serialize, P: Purpose, A: Actor<S>, S: Signature> Signed<T, P, A, S> {
+    /// Signs `value` for purpose `P` with `signing_actor`, mixing `P::TAG`
+    /// into the prehash so the resulting signature only verifies as a
+    /// `Signed<T, P>`.
+    pub fn sign<SA: SigningActor<A, S>>(signing_actor: &SA, value: T) -> Result<Self, Error> {
+        let bytes = purpose_scoped_bytes::<T, P>(&value)?;
+        let sig = signing_actor.try_sign(&bytes)?;
+        Ok(Self {
+            value,
+            sig,
+            actor: signing_actor.actor(),
+            _purpose: PhantomData,
+        })
+    }
+
+    /// Verifies `sig` over `value` as purpose `P` under `actor`. A signature
+    /// made for a different `Purpose` (different `TAG`) fails here even if
+    /// it is otherwise a valid signature over `value`'s bytes.
+    pub fn verify(actor: A, value: T, sig: S) -> Result<Self, Error> {
+        let bytes = purpose_scoped_bytes::<T, P>(&value)?;
+        actor.verify(&bytes, &sig)?;
+        Ok(Self {
+            value,
+            sig,
+            actor,
+            _purpose: PhantomData,
+        })
+    }
+}
@@ -16,7 +16,7 @@ pub enum Error {
     FailedVerification,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PublicKey(u64);
 
 impl PublicKey {
@@ -73,5 +73,34 @@ impl SecretKey {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Signature(u64);
+
+/// [`crate::scheme::Scheme`] impl so `State` can eventually be generic over
+/// which backend it's wired to rather than selected at compile time via the
+/// `bad_crypto` feature; see that module's doc comment.
+#[derive(Clone, Debug)]
+pub struct BadCryptoScheme;
+
+impl crate::scheme::Scheme for BadCryptoScheme {
+    type PublicKey = PublicKey;
+    type SecretKey = SecretKey;
+    type Signature = Signature;
+    type Error = Error;
+
+    fn public_key(secret_key: &Self::SecretKey) -> Self::PublicKey {
+        secret_key.public_key()
+    }
+
+    fn sign(secret_key: &Self::SecretKey, msg: &[u8]) -> Self::Signature {
+        secret_key.sign(msg)
+    }
+
+    fn verify(
+        public_key: &Self::PublicKey,
+        msg: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<(), Self::Error> {
+        public_key.verify(msg, signature)
+    }
+}
@@ -1,6 +1,8 @@
-use blsttc::{serde_impl::SerdeSecret, SecretKeyShare};
+use blsttc::poly::{Commitment, Poly};
+use blsttc::{serde_impl::SerdeSecret, PublicKeySet, SecretKeyShare};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -64,6 +66,19 @@ impl SecretKey {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signature(blsttc::SignatureShare);
 
+impl Signature {
+    pub(crate) fn from_share(share: blsttc::SignatureShare) -> Self {
+        Self(share)
+    }
+
+    /// The raw share this `Signature` wraps, needed to feed [`aggregate`]
+    /// from outside the crate (there's no other way to get at it, since a
+    /// `ThresholdGroup`'s dealt shares are otherwise only exposed signed).
+    pub fn share(&self) -> &blsttc::SignatureShare {
+        &self.0
+    }
+}
+
 impl PartialOrd for PublicKey {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -76,6 +91,47 @@ impl Ord for PublicKey {
     }
 }
 
+impl Hash for PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+impl Hash for Signature {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+/// [`crate::scheme::Scheme`] impl so `State` can eventually be generic over
+/// which backend it's wired to rather than selected at compile time via the
+/// `blsttc` feature; see that module's doc comment.
+#[derive(Clone, Debug)]
+pub struct BlsttcScheme;
+
+impl crate::scheme::Scheme for BlsttcScheme {
+    type PublicKey = PublicKey;
+    type SecretKey = SecretKey;
+    type Signature = Signature;
+    type Error = Error;
+
+    fn public_key(secret_key: &Self::SecretKey) -> Self::PublicKey {
+        secret_key.public_key()
+    }
+
+    fn sign(secret_key: &Self::SecretKey, msg: &[u8]) -> Self::Signature {
+        secret_key.sign(msg)
+    }
+
+    fn verify(
+        public_key: &Self::PublicKey,
+        msg: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<(), Self::Error> {
+        public_key.verify(msg, signature)
+    }
+}
+
 impl PartialOrd for Signature {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -87,3 +143,79 @@ impl Ord for Signature {
         self.0.to_bytes().cmp(&other.0.to_bytes())
     }
 }
+
+/// A dealer that samples a random degree-`threshold` polynomial and hands out
+/// one [`SecretKeyShare`] per member, following the verifiable secret sharing
+/// scheme `threshold_crypto` (and by extension `blsttc`) is built on.
+///
+/// `t + 1` of the resulting shares are required to reconstruct a signature
+/// under the group's [`blsttc::PublicKey`], via [`aggregate`].
+pub struct ThresholdGroup {
+    threshold: usize,
+    commitment: Commitment,
+    shares: Vec<SecretKeyShare>,
+}
+
+impl ThresholdGroup {
+    /// Deals shares to `n` members for a `threshold`-of-`n` scheme, i.e.
+    /// `threshold + 1` shares are required to reconstruct a signature.
+    pub fn deal(threshold: usize, n: usize, mut rng: impl Rng + CryptoRng) -> Self {
+        let poly = Poly::random(threshold, &mut rng);
+        let commitment = poly.commitment();
+        let shares = (0..n)
+            .map(|i| SecretKeyShare::from_mut(&mut poly.evaluate(i + 1)))
+            .collect();
+
+        Self {
+            threshold,
+            commitment,
+            shares,
+        }
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The `Commitment` published alongside the shares so recipients can
+    /// verify their share against the dealer's polynomial.
+    pub fn commitment(&self) -> &Commitment {
+        &self.commitment
+    }
+
+    pub fn public_key_set(&self) -> PublicKeySet {
+        PublicKeySet::from(self.commitment.clone())
+    }
+
+    pub fn public_key(&self) -> blsttc::PublicKey {
+        self.public_key_set().public_key()
+    }
+
+    pub fn secret_key_share(&self, index: usize) -> Option<SecretKey> {
+        self.shares.get(index).cloned().map(SecretKey::from)
+    }
+}
+
+/// Combine `t + 1` signature shares, produced over the same message by
+/// distinct members of a [`ThresholdGroup`], into a single `blsttc::Signature`
+/// that verifies against the group's public key.
+///
+/// Each share is first checked against the public key share the
+/// `public_key_set` derives for its index; a share that fails this check
+/// means a dishonest or buggy signer, not a reconstruction failure, so we
+/// report it as `Error::InvalidSignature` before attempting to combine.
+pub fn aggregate(
+    public_key_set: &PublicKeySet,
+    msg: &[u8],
+    shares: &[(usize, blsttc::SignatureShare)],
+) -> Result<blsttc::Signature, Error> {
+    for (index, share) in shares {
+        if !public_key_set.public_key_share(index).verify(share, msg) {
+            return Err(Error::InvalidSignature);
+        }
+    }
+
+    public_key_set
+        .combine_signatures(shares.iter().map(|(index, share)| (*index, share)))
+        .map_err(|_| Error::InvalidSignature)
+}
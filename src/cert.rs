@@ -0,0 +1,169 @@
+//! Compressed membership certificates for the blsttc backend.
+//!
+//! `Ballot::SuperMajority(BTreeSet<SignedVote>)` recursively embeds every
+//! vote that went into a decision, so `State::history` (and anything that
+//! walks it, like onboarding via `anti_entropy`) pays for the full
+//! transitive closure of the votes a super-majority saw. A
+//! [`MembershipCertificate`] replaces that with a single threshold-combined
+//! BLS signature over the winning `(reconfigs, gen)`, verifiable against the
+//! previous generation's [`crate::dkg::Outcome::public_key_set`] alone.
+//!
+//! This lives alongside `State::history` rather than replacing it: `history`
+//! is what onboards `ed25519`/`bad_crypto` deployments, which have no
+//! aggregate signature scheme to compress into, so it stays the portable
+//! representation. `State::cert_history` is the blsttc-only compressed
+//! record a generation gets once enough [`SignedVote::cert_share`]s have
+//! been collected to combine into one.
+//!
+//! [`Checkpoint`] is the same idea applied to `history` itself rather than a
+//! single generation's decision: every `JUSTIFICATION_PERIOD` generations,
+//! `State` combines enough [`SignedVote::checkpoint_share`]s into one
+//! self-contained proof of the full member set at that generation, so
+//! `State::members` and `State::prune_history` don't need every vote back to
+//! genesis to establish trust in the current member set.
+//!
+//! [`KeySuccession`] chains one generation's key to the next: every
+//! generation mints its own independent group key (see the module doc on
+//! `crate::dkg` for why `State` doesn't run a proactive secret-resharing
+//! protocol to keep the same key across a reconfiguration), so on its own a
+//! new generation's key is just as trustworthy as a key presented out of
+//! nowhere. Whichever members complete both generations' DKG sessions --
+//! usually everyone but whoever just joined or left -- sign the new key
+//! under the old one's threshold key, so a verifier who already trusts
+//! generation `gen - 1`'s key can extend that trust to `gen`'s one
+//! generation at a time via [`State::key_successions`], without replaying
+//! either generation's DKG.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::brb_membership::{Generation, Reconfig};
+use crate::{Error, PublicKey};
+
+/// Proof that `signers` -- the members of generation `gen`'s predecessor
+/// whose verified shares were combined, at least that predecessor's DKG
+/// threshold + 1 of them (not necessarily a 2/3 majority) -- attest that
+/// `new_public_key` is the group key generation `gen`'s members minted for
+/// themselves. See the module doc comment for what this does and doesn't
+/// give you in place of resharing the same secret.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeySuccession {
+    pub gen: Generation,
+    pub new_public_key: blsttc::PublicKey,
+    pub signers: BTreeSet<PublicKey>,
+    pub signature: blsttc::Signature,
+}
+
+impl KeySuccession {
+    /// The bytes every signer's [`SignedVote::key_succession_share`] is a
+    /// threshold share of, and what the combined `signature` must verify
+    /// against.
+    ///
+    /// [`SignedVote::key_succession_share`]: crate::brb_membership::SignedVote::key_succession_share
+    pub fn signing_bytes(new_public_key: &blsttc::PublicKey, gen: Generation) -> Result<Vec<u8>, Error> {
+        Ok(bincode::serialize(&(new_public_key.to_bytes().to_vec(), gen))?)
+    }
+}
+
+/// Proof that `signers` — a 2/3 majority of the prior generation's members —
+/// agreed on `reconfigs` for `gen`, as one combined BLS signature rather than
+/// a nested set of individual votes. This is the "decision certificate" for
+/// a generation: anyone holding the prior generation's
+/// [`crate::dkg::Outcome::public_key_set`] can verify it without replaying
+/// the votes that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipCertificate {
+    pub gen: Generation,
+    pub reconfigs: BTreeSet<Reconfig>,
+    pub signers: BTreeSet<PublicKey>,
+    pub signature: blsttc::Signature,
+}
+
+impl MembershipCertificate {
+    /// The bytes every signer's [`SignedVote::cert_share`] is a threshold
+    /// share of, and what the combined `signature` must verify against.
+    pub fn signing_bytes(reconfigs: &BTreeSet<Reconfig>, gen: Generation) -> Result<Vec<u8>, Error> {
+        Ok(bincode::serialize(&(reconfigs, gen))?)
+    }
+}
+
+/// Ordered on `(gen, reconfigs, signers, signature bytes)` so a
+/// `Ballot::Certified(MembershipCertificate)` can live in the same
+/// `BTreeSet<SignedVote>`/`Ord`-derived types as every other `Ballot`
+/// variant; `blsttc::Signature` has no ordering of its own, so its raw
+/// bytes stand in, exactly like `crate::blsttc::Signature`'s own `Ord` impl.
+impl PartialEq for MembershipCertificate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for MembershipCertificate {}
+
+impl PartialOrd for MembershipCertificate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MembershipCertificate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.gen
+            .cmp(&other.gen)
+            .then_with(|| self.reconfigs.cmp(&other.reconfigs))
+            .then_with(|| self.signers.cmp(&other.signers))
+            .then_with(|| self.signature.to_bytes().cmp(&other.signature.to_bytes()))
+    }
+}
+
+/// A justification checkpoint: proof that `members` was the complete member
+/// set as of `gen`, signed by `signers` — a 2/3 majority of `gen`'s
+/// predecessor members — as one combined BLS signature. `State` keeps one of
+/// these every `JUSTIFICATION_PERIOD` generations so it can discard the
+/// `history`/`cert_history` entries it covers; see `State::prune_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub gen: Generation,
+    pub members: BTreeSet<PublicKey>,
+    pub signers: BTreeSet<PublicKey>,
+    pub signature: blsttc::Signature,
+}
+
+impl Checkpoint {
+    /// The bytes every signer's [`SignedVote::checkpoint_share`] is a
+    /// threshold share of, and what the combined `signature` must verify
+    /// against.
+    pub fn signing_bytes(members: &BTreeSet<PublicKey>, gen: Generation) -> Result<Vec<u8>, Error> {
+        Ok(bincode::serialize(&(members, gen))?)
+    }
+}
+
+/// See [`MembershipCertificate`]'s `Ord` impl: same reasoning, ordered on
+/// `(gen, members, signers, signature bytes)` so a
+/// `Ballot::Checkpointed(Checkpoint)` can live in the same `BTreeSet<SignedVote>`
+/// as every other `Ballot` variant.
+impl PartialEq for Checkpoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Checkpoint {}
+
+impl PartialOrd for Checkpoint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Checkpoint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.gen
+            .cmp(&other.gen)
+            .then_with(|| self.members.cmp(&other.members))
+            .then_with(|| self.signers.cmp(&other.signers))
+            .then_with(|| self.signature.to_bytes().cmp(&other.signature.to_bytes()))
+    }
+}
+
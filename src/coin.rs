@@ -0,0 +1,42 @@
+//! A shared, unbiased "common coin" used to break persistent split votes,
+//! following honey-badger's binary-agreement construction: once a threshold
+//! of members sign the same `(gen, round)` with their share of the
+//! generation's DKG key, combining those shares yields a signature (and
+//! hence a bit) that's unpredictable before the threshold is reached but
+//! identical for every honest node once it is.
+//!
+//! [`crate::brb_membership::State`] only reaches for this once a generation
+//! has been flagged split for more than one round (see
+//! `State::handle_signed_vote`); an isolated split is still resolved the
+//! usual way, by merging and re-counting votes.
+
+use std::collections::BTreeSet;
+
+use crate::brb_membership::{Generation, Reconfig};
+use crate::Error;
+
+pub type Round = u64;
+
+/// The bytes every member's coin share is a threshold signature share of.
+pub fn signing_bytes(gen: Generation, round: Round) -> Result<Vec<u8>, Error> {
+    Ok(bincode::serialize(&(gen, round))?)
+}
+
+/// The unbiased bit every honest node derives from the same combined
+/// threshold signature over `(gen, round)`: the low bit of its byte
+/// representation.
+pub fn bit(signature: &blsttc::Signature) -> bool {
+    signature.to_bytes()[0] & 1 == 1
+}
+
+/// Deterministically picks between two tied reconfig sets using the shared
+/// coin's bit: `false` selects the lexicographically smaller set, `true`
+/// the larger.
+pub fn pick(bit: bool, a: BTreeSet<Reconfig>, b: BTreeSet<Reconfig>) -> BTreeSet<Reconfig> {
+    let (smaller, larger) = if a <= b { (a, b) } else { (b, a) };
+    if bit {
+        larger
+    } else {
+        smaller
+    }
+}
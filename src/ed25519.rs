@@ -1,6 +1,9 @@
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use signature::{Signer, Verifier};
+use std::hash::{Hash, Hasher};
+
+pub mod frost;
 
 pub type Error = signature::Error;
 
@@ -15,6 +18,16 @@ impl PublicKey {
     pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
         self.0.verify(msg, &signature.0)
     }
+
+    /// Builds a `PublicKey` from a compressed Edwards point, e.g. a FROST
+    /// group public key derived from a dealt or DKG'd secret polynomial.
+    pub fn from_compressed(point: curve25519_dalek::edwards::CompressedEdwardsY) -> Self {
+        Self(ed25519::PublicKey::from_bytes(point.as_bytes()).expect("valid compressed point"))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
 }
 
 impl core::fmt::Debug for PublicKey {
@@ -50,6 +63,14 @@ impl SecretKey {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signature(ed25519::Signature);
 
+impl Signature {
+    /// Builds a `Signature` from its raw `R || s` encoding, as produced by
+    /// combining FROST signature shares in [`frost::aggregate`].
+    pub(crate) fn from_raw_bytes(bytes: [u8; 64]) -> Result<Self, frost::Error> {
+        Ok(Self(ed25519::Signature::new(bytes)))
+    }
+}
+
 impl PartialOrd for PublicKey {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
@@ -73,3 +94,57 @@ impl Ord for Signature {
         self.0.to_bytes().cmp(&other.0.to_bytes())
     }
 }
+
+impl Hash for PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+/// Verifies every `(public_key, message, signature)` triple in one
+/// `ed25519_dalek::verify_batch` call instead of one `PublicKey::verify` per
+/// triple — used by `State::validate_signed_vote` to check a `SignedVote`'s
+/// recursively nested votes (its `Merge`/`SuperMajority` ballots can embed
+/// many) without quadratic per-signature work on large merged ballots.
+pub fn verify_batch(triples: &[(&PublicKey, &[u8], &Signature)]) -> Result<(), Error> {
+    let messages: Vec<&[u8]> = triples.iter().map(|(_, msg, _)| *msg).collect();
+    let signatures: Vec<ed25519::Signature> = triples.iter().map(|(_, _, sig)| sig.0).collect();
+    let public_keys: Vec<ed25519::PublicKey> = triples.iter().map(|(pk, _, _)| pk.0).collect();
+
+    ed25519::verify_batch(&messages, &signatures, &public_keys)
+}
+
+impl Hash for Signature {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bytes().hash(state);
+    }
+}
+
+/// [`crate::scheme::Scheme`] impl so `State` can eventually be generic over
+/// which backend it's wired to rather than selected at compile time via the
+/// `ed25519` feature; see that module's doc comment.
+#[derive(Clone, Debug)]
+pub struct Ed25519Scheme;
+
+impl crate::scheme::Scheme for Ed25519Scheme {
+    type PublicKey = PublicKey;
+    type SecretKey = SecretKey;
+    type Signature = Signature;
+    type Error = Error;
+
+    fn public_key(secret_key: &Self::SecretKey) -> Self::PublicKey {
+        secret_key.public_key()
+    }
+
+    fn sign(secret_key: &Self::SecretKey, msg: &[u8]) -> Self::Signature {
+        secret_key.sign(msg)
+    }
+
+    fn verify(
+        public_key: &Self::PublicKey,
+        msg: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<(), Self::Error> {
+        public_key.verify(msg, signature)
+    }
+}
@@ -0,0 +1,253 @@
+//! FROST (Flexible Round-Optimized Schnorr Threshold signatures) over the
+//! ed25519 scalar field, giving the ed25519 backend a threshold signing mode
+//! without requiring pairings the way the blsttc backend's
+//! [`crate::blsttc::ThresholdGroup`] does.
+//!
+//! Key generation distributes Shamir shares `s_i` of a group secret `s` with
+//! group key `A = s*G`. Signing is the usual two-round FROST protocol:
+//! round 1 publishes per-signer nonce commitments `(D_i, E_i)`, round 2 mixes
+//! those commitments into a binding factor per signer and combines the
+//! resulting shares `z_i` into a standard ed25519/Schnorr signature that
+//! verifies under `A` via the normal `Verifier<Sig>` path.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use ed25519::{Digest, Sha512};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::ed25519::{PublicKey, Signature};
+
+/// Domain separation tag, kept aligned with `actor::ed25519::CONTEXT` so
+/// FROST challenges can never be confused with a one-shot dalek signature.
+const CONTEXT: &[u8] = b"BRBEd25519FrostSignerPrehashedContext";
+
+pub type Identifier = u16;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("a nonce commitment was reused in round 2 of signing")]
+    NonceReused,
+    #[error("no commitment was published for signer {0}")]
+    MissingCommitment(Identifier),
+    #[error("fewer signers ({0}) than the group threshold ({1}) took part")]
+    NotEnoughSigners(usize, usize),
+    #[error("commitment from signer {0} does not decompress to a valid curve point")]
+    InvalidCommitment(Identifier),
+}
+
+/// This signer's long-lived share of the group secret key, produced by
+/// [`keygen`].
+#[derive(Clone)]
+pub struct KeyShare {
+    pub id: Identifier,
+    pub secret: Scalar,
+    pub group_public_key: PublicKey,
+}
+
+/// Distributes Shamir shares of a fresh random group secret across `n`
+/// signers for a `threshold`-of-`n` scheme (`threshold + 1` signers must
+/// cooperate to produce a signature). This is a dealt keygen; a dealerless
+/// round-robin variant can be layered on top the same way blsttc's DKG would.
+pub fn keygen(
+    threshold: usize,
+    ids: &BTreeSet<Identifier>,
+    mut rng: impl Rng + CryptoRng,
+) -> BTreeMap<Identifier, KeyShare> {
+    let coefficients: Vec<Scalar> = (0..=threshold).map(|_| Scalar::random(&mut rng)).collect();
+    let group_secret = coefficients[0];
+    let group_point = &group_secret * &ED25519_BASEPOINT_TABLE;
+    let group_public_key = point_to_public_key(&group_point);
+
+    ids.iter()
+        .map(|&id| {
+            let secret = evaluate_polynomial(&coefficients, id);
+            (
+                id,
+                KeyShare {
+                    id,
+                    secret,
+                    group_public_key,
+                },
+            )
+        })
+        .collect()
+}
+
+/// The pair of nonce commitments a signer publishes in round 1, and the
+/// matching secret nonces it must keep and use exactly once in round 2.
+#[derive(Clone)]
+pub struct SigningNonces {
+    d: Scalar,
+    e: Scalar,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct NonceCommitment {
+    pub id: Identifier,
+    pub big_d: [u8; 32],
+    pub big_e: [u8; 32],
+}
+
+/// Round 1: sample this signer's nonce pair and publish its commitments.
+pub fn commit(id: Identifier, mut rng: impl Rng + CryptoRng) -> (SigningNonces, NonceCommitment) {
+    let d = Scalar::random(&mut rng);
+    let e = Scalar::random(&mut rng);
+    let big_d = (&d * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+    let big_e = (&e * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+    (SigningNonces { d, e }, NonceCommitment { id, big_d, big_e })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SignatureShare {
+    pub id: Identifier,
+    pub z: [u8; 32],
+}
+
+/// Round 2: given the full set of published commitments `commitments` (at
+/// least `threshold + 1` of them), produce this signer's share `z_i` of the
+/// joint signature over `msg`.
+///
+/// `nonces` must be the exact pair returned by this signer's own [`commit`]
+/// call for this round. It's taken by value and dropped at the end of this
+/// call so a second `sign` can't be called with the same nonce pair -- the
+/// compiler rejects the reuse rather than relying on callers to remember to
+/// discard it.
+pub fn sign(
+    key_share: &KeyShare,
+    nonces: SigningNonces,
+    msg: &[u8],
+    threshold: usize,
+    commitments: &BTreeSet<NonceCommitment>,
+) -> Result<SignatureShare, Error> {
+    let signer_ids: BTreeSet<Identifier> = commitments.iter().map(|c| c.id).collect();
+    let our_commitment = commitments
+        .iter()
+        .find(|c| c.id == key_share.id)
+        .ok_or(Error::MissingCommitment(key_share.id))?;
+
+    if our_commitment.big_d != (&nonces.d * &ED25519_BASEPOINT_TABLE).compress().to_bytes()
+        || our_commitment.big_e != (&nonces.e * &ED25519_BASEPOINT_TABLE).compress().to_bytes()
+    {
+        return Err(Error::NonceReused);
+    }
+
+    let group_commitment = group_commitment_point(msg, threshold, commitments)?;
+    let challenge = challenge_scalar(&group_commitment, &key_share.group_public_key, msg);
+    let lambda = lagrange_coefficient(key_share.id, &signer_ids);
+    let rho = binding_factor(key_share.id, msg, commitments);
+
+    let z = nonces.d + nonces.e * rho + lambda * key_share.secret * challenge;
+
+    Ok(SignatureShare {
+        id: key_share.id,
+        z: z.to_bytes(),
+    })
+}
+
+/// Combine the per-signer shares produced by [`sign`] into one ed25519
+/// Schnorr signature that verifies under the group's public key via the
+/// crate's normal `Verifier<Sig>` path.
+pub fn aggregate(
+    msg: &[u8],
+    threshold: usize,
+    commitments: &BTreeSet<NonceCommitment>,
+    shares: &[SignatureShare],
+) -> Result<Signature, Error> {
+    let group_commitment = group_commitment_point(msg, threshold, commitments)?;
+
+    let mut z = Scalar::zero();
+    for share in shares {
+        z += Scalar::from_canonical_bytes(share.z).unwrap_or_else(Scalar::zero);
+    }
+
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(group_commitment.compress().as_bytes());
+    bytes[32..].copy_from_slice(z.as_bytes());
+
+    Signature::from_raw_bytes(bytes)
+}
+
+fn group_commitment_point(
+    msg: &[u8],
+    threshold: usize,
+    commitments: &BTreeSet<NonceCommitment>,
+) -> Result<EdwardsPoint, Error> {
+    let required = threshold + 1;
+    if commitments.len() < required {
+        return Err(Error::NotEnoughSigners(commitments.len(), required));
+    }
+
+    let mut acc = EdwardsPoint::identity();
+    for commitment in commitments {
+        let rho = binding_factor(commitment.id, msg, commitments);
+        let big_d = decompress(&commitment.big_d).ok_or(Error::InvalidCommitment(commitment.id))?;
+        let big_e = decompress(&commitment.big_e).ok_or(Error::InvalidCommitment(commitment.id))?;
+        acc += big_d + big_e * rho;
+    }
+    Ok(acc)
+}
+
+/// `rho_i = H(i, m, B)`: binds each signer's nonce pair to the message and
+/// every other signer's commitments, which is what makes the aggregate
+/// commitment unpredictable to an attacker controlling a minority of nonces.
+fn binding_factor(id: Identifier, msg: &[u8], commitments: &BTreeSet<NonceCommitment>) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(CONTEXT);
+    hasher.update(b"rho");
+    hasher.update(id.to_be_bytes());
+    hasher.update(msg);
+    for commitment in commitments {
+        hasher.update(commitment.id.to_be_bytes());
+        hasher.update(commitment.big_d);
+        hasher.update(commitment.big_e);
+    }
+    Scalar::from_hash(hasher)
+}
+
+fn challenge_scalar(group_commitment: &EdwardsPoint, group_public_key: &PublicKey, msg: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(CONTEXT);
+    hasher.update(b"challenge");
+    hasher.update(group_commitment.compress().as_bytes());
+    hasher.update(group_public_key.as_bytes());
+    hasher.update(msg);
+    Scalar::from_hash(hasher)
+}
+
+fn lagrange_coefficient(id: Identifier, signer_ids: &BTreeSet<Identifier>) -> Scalar {
+    let x_i = Scalar::from(id as u64 + 1);
+    let mut num = Scalar::one();
+    let mut den = Scalar::one();
+    for &other in signer_ids {
+        if other == id {
+            continue;
+        }
+        let x_j = Scalar::from(other as u64 + 1);
+        num *= x_j;
+        den *= x_j - x_i;
+    }
+    num * den.invert()
+}
+
+/// Signer `id`'s share is the polynomial at `x = id + 1`, so no share is
+/// ever the group secret itself (the polynomial at `x = 0`).
+fn evaluate_polynomial(coefficients: &[Scalar], id: Identifier) -> Scalar {
+    let x = Scalar::from(id as u64 + 1);
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, coeff| acc * x + coeff)
+}
+
+fn point_to_public_key(point: &EdwardsPoint) -> PublicKey {
+    PublicKey::from_compressed(point.compress())
+}
+
+fn decompress(bytes: &[u8; 32]) -> Option<EdwardsPoint> {
+    CompressedEdwardsY(*bytes).decompress()
+}
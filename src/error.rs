@@ -44,11 +44,34 @@ pub enum Error {
         gen: Generation,
         pending_gen: Generation,
     },
+    #[cfg(feature = "blsttc")]
+    #[error("A key succession share is always for the current generation: vote gen {vote_gen} != {gen}")]
+    KeySuccessionShareNotForCurrentGeneration {
+        vote_gen: Generation,
+        gen: Generation,
+    },
+    #[cfg(feature = "blsttc")]
+    #[error("A DKG Part/Ack is always for the current generation: vote gen {vote_gen} != {gen}")]
+    DkgMsgNotForCurrentGeneration {
+        vote_gen: Generation,
+        gen: Generation,
+    },
+    #[cfg(feature = "blsttc")]
+    #[error("DKG Part/Ack claims to be authored by {claimed_author:?} but was signed by {voter:?}")]
+    DkgMsgAuthorMismatch {
+        voter: PublicKey,
+        claimed_author: PublicKey,
+    },
     #[error("({public_key} is not in {members:?})")]
     NonMember {
         public_key: PublicKey,
         members: BTreeSet<PublicKey>,
     },
+    #[error("Vote from non-member: {voter} is not in {members:?}")]
+    VoteFromNonMember {
+        voter: PublicKey,
+        members: BTreeSet<PublicKey>,
+    },
     #[error("Voter changed their mind: {reconfigs:?}")]
     VoterChangedMind {
         reconfigs: BTreeSet<(PublicKey, Reconfig)>,
@@ -67,6 +90,9 @@ pub enum Error {
     #[error("Failed to encode with bincode")]
     Encoding(#[from] bincode::Error),
 
+    #[error("Fault proof does not actually demonstrate the misbehavior it claims")]
+    InvalidFaultProof,
+
     #[cfg(feature = "ed25519")]
     #[error("Ed25519 Error {0}")]
     Ed25519(#[from] crate::ed25519::Error),
@@ -75,6 +101,34 @@ pub enum Error {
     #[error("Blsttc Error {0}")]
     Blsttc(#[from] crate::blsttc::Error),
 
+    #[cfg(feature = "blsttc")]
+    #[error("DKG Error {0}")]
+    Dkg(#[from] crate::dkg::Error),
+
+    #[cfg(feature = "blsttc")]
+    #[error("Membership certificate for gen {gen} is not valid: {signers:?} is not a 2/3 majority of {members:?}, or its signature does not verify against the previous generation's group key")]
+    InvalidMembershipCertificate {
+        gen: Generation,
+        signers: BTreeSet<PublicKey>,
+        members: BTreeSet<PublicKey>,
+    },
+
+    #[cfg(feature = "blsttc")]
+    #[error("Checkpoint for gen {gen} is not valid: {signers:?} is not a 2/3 majority of {members:?}, or its signature does not verify against the previous generation's group key")]
+    InvalidCheckpoint {
+        gen: Generation,
+        signers: BTreeSet<PublicKey>,
+        members: BTreeSet<PublicKey>,
+    },
+
+    #[cfg(feature = "blsttc")]
+    #[error("Key succession for gen {gen} is not valid: {signers:?} is not a 2/3 majority of {members:?}")]
+    InvalidKeySuccession {
+        gen: Generation,
+        signers: BTreeSet<PublicKey>,
+        members: BTreeSet<PublicKey>,
+    },
+
     #[cfg(feature = "bad_crypto")]
     #[error("Failed Signature Verification")]
     BadCrypto(#[from] crate::bad_crypto::Error),
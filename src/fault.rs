@@ -0,0 +1,77 @@
+//! Byzantine fault evidence.
+//!
+//! `State::handle_signed_vote` used to collapse a bad packet straight into
+//! an `Error` the caller could only assert on and drop; there was no way to
+//! hang onto *why* a peer was misbehaving or hand that evidence to anyone
+//! else. A [`Fault`] is the self-contained alternative: proof that can be
+//! independently re-verified by any member with nothing but the proof
+//! itself, so honest nodes can persist and gossip it instead.
+
+use serde::{Deserialize, Serialize};
+
+use crate::brb_membership::SignedVote;
+use crate::{Error, PublicKey};
+
+/// What a [`Fault`] is evidence of.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FaultKind {
+    /// `signed_vote.sig` does not verify against `signed_vote.voter`.
+    InvalidSignature { signed_vote: SignedVote },
+    /// `signed_vote.sig` verifies against `signer`, not the `voter` it
+    /// claims to be cast by.
+    Impersonation {
+        signed_vote: SignedVote,
+        signer: PublicKey,
+    },
+    /// `a` and `b` are two incompatible votes cast by the same voter at the
+    /// same generation: neither supersedes the other, so the voter cast two
+    /// genuinely conflicting ballots rather than just changing its mind.
+    Equivocation { a: SignedVote, b: SignedVote },
+}
+
+/// Self-contained proof of misbehavior: `reporter` observed `kind`, and
+/// [`Fault::verify`] lets any member re-run the checks that justify it
+/// without trusting `reporter`'s word for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fault {
+    pub reporter: PublicKey,
+    pub kind: FaultKind,
+}
+
+impl Fault {
+    /// Re-verifies that `self.kind` is genuine. Returns
+    /// [`Error::InvalidFaultProof`] if the embedded votes don't actually
+    /// demonstrate the misbehavior claimed.
+    pub fn verify(&self) -> Result<(), Error> {
+        match &self.kind {
+            FaultKind::InvalidSignature { signed_vote } => {
+                let blob_bytes = bincode::serialize(&(&signed_vote.ballot, &signed_vote.gen))?;
+                match signed_vote.voter.verify(&blob_bytes, &signed_vote.sig) {
+                    Ok(()) => Err(Error::InvalidFaultProof),
+                    Err(_) => Ok(()),
+                }
+            }
+            FaultKind::Impersonation { signed_vote, signer } => {
+                let blob_bytes = bincode::serialize(&(&signed_vote.ballot, &signed_vote.gen))?;
+                if signer == &signed_vote.voter
+                    || signed_vote.voter.verify(&blob_bytes, &signed_vote.sig).is_ok()
+                {
+                    return Err(Error::InvalidFaultProof);
+                }
+                signer.verify(&blob_bytes, &signed_vote.sig)?;
+                Ok(())
+            }
+            FaultKind::Equivocation { a, b } => {
+                if a.voter != b.voter || a.gen != b.gen || a.supersedes(b) || b.supersedes(a) {
+                    return Err(Error::InvalidFaultProof);
+                }
+
+                let a_bytes = bincode::serialize(&(&a.ballot, &a.gen))?;
+                let b_bytes = bincode::serialize(&(&b.ballot, &b.gen))?;
+                a.voter.verify(&a_bytes, &a.sig)?;
+                b.voter.verify(&b_bytes, &b.sig)?;
+                Ok(())
+            }
+        }
+    }
+}
@@ -7,23 +7,42 @@
 ))]
 compile_error!("Must enable either `ed25519`, `blsttc` or `bad_crypto` feature flags");
 
+pub mod actor;
 pub mod brb_membership;
+pub mod fault;
+pub mod scheme;
 
 #[cfg(feature = "bad_crypto")]
 pub mod bad_crypto;
 #[cfg(feature = "blsttc")]
 pub mod blsttc;
+#[cfg(feature = "blsttc")]
+pub mod cert;
+#[cfg(feature = "blsttc")]
+pub mod coin;
+#[cfg(feature = "blsttc")]
+pub mod dkg;
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
 
-pub use crate::brb_membership::{Ballot, Generation, Reconfig, SignedVote, State, Vote, VoteMsg};
+pub use crate::actor::{Actor, Sig, SigningActor};
+pub use crate::scheme::Scheme;
+pub use crate::brb_membership::{
+    Ballot, Generation, LogicalClock, Reconfig, SignedVote, State, Vote, VoteMsg, Weight,
+};
+pub use crate::fault::{Fault, FaultKind};
 
-#[cfg(feature = "bad_crypto")]
-pub use crate::bad_crypto::{PublicKey, SecretKey, Signature};
-#[cfg(feature = "blsttc")]
-pub use crate::blsttc::{PublicKey, SecretKey, Signature};
-#[cfg(feature = "ed25519")]
-pub use crate::ed25519::{PublicKey, SecretKey, Signature};
+/// This build's `PublicKey`/`SecretKey`/`Signature`, defined in terms of
+/// whichever backend's [`Scheme`] impl [`scheme::DefaultScheme`] resolves to
+/// rather than re-exporting that backend's types directly, so `State` and
+/// everything downstream of it is already written against `Scheme`'s
+/// associated types even though `State` itself isn't generic over `Scheme`
+/// yet. This is prep work only: the backend is still picked once, at compile
+/// time, by the feature flags gated above, not at runtime; see the module
+/// doc comment on [`scheme`] for what's still missing before that's true.
+pub type PublicKey = <scheme::DefaultScheme as Scheme>::PublicKey;
+pub type SecretKey = <scheme::DefaultScheme as Scheme>::SecretKey;
+pub type Signature = <scheme::DefaultScheme as Scheme>::Signature;
 
 pub mod error;
 pub use crate::error::Error;
@@ -0,0 +1,67 @@
+//! A `Scheme` bundles one crypto backend's `PublicKey`/`SecretKey`/
+//! `Signature` types together with the `sign`/`verify` pair that relates
+//! them. This module is scaffolding for, not a delivery of, runtime-pluggable
+//! signature backends: the `compile_error!` in `lib.rs` still forces exactly
+//! one of `ed25519`, `blsttc`, or `bad_crypto` per build, and nothing in this
+//! crate can select a backend at runtime or mix two backends in one binary.
+//! What this module does do is give the parts of the crate that are
+//! currently hard-wired to whichever backend is feature-enabled a single
+//! trait to eventually be generic over, instead of each needing its own
+//! per-backend `#[cfg]` arm.
+//!
+//! [`Ed25519Scheme`](crate::ed25519::Ed25519Scheme),
+//! [`BlsttcScheme`](crate::blsttc::BlsttcScheme), and
+//! [`BadCryptoScheme`](crate::bad_crypto::BadCryptoScheme) are the three
+//! impls; each is a unit struct, since the backend types themselves (not the
+//! scheme marker) are what carries key material.
+//!
+//! [`DefaultScheme`] is whichever of the three this build's `ed25519`/
+//! `blsttc`/`bad_crypto` feature flag selects (see the `compile_error!` in
+//! `lib.rs`), and `crate::{PublicKey, SecretKey, Signature}` are defined as
+//! `DefaultScheme`'s associated types rather than re-exporting the chosen
+//! backend module's types directly -- so `State` and everything downstream
+//! of it (`Vote`/`SignedVote`/`Ballot`, `error::Error`) is already written
+//! against `Scheme`'s associated types under the hood, even though none of
+//! those signatures spell out `<S: Scheme>` yet and the feature-flag
+//! selection happens at compile time, once, crate-wide. Actually lifting that
+//! restriction -- `State<S: Scheme>`, a single binary able to pick a backend
+//! at runtime -- needs `error::Error`'s `Ed25519`/`Blsttc`/`BadCrypto`
+//! variants to become `S::Error` (boxed, so `Error` doesn't itself become
+//! generic) and every `PublicKey`/`SecretKey`/`Signature` in
+//! `brb_membership`, `Vote`/`SignedVote`/`Ballot`, and `Packet`-shaped
+//! transport types to take an `S` parameter. That's a cross-cutting rewrite
+//! this module does not attempt; treat this as the trait-definition step
+//! only, not as the runtime-pluggable backend the original request asked for.
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// One crypto backend's key/signature types and the operations that relate
+/// them. See the module doc comment for why `State` isn't generic over this
+/// yet.
+pub trait Scheme: Clone + Debug {
+    type PublicKey: Ord + Hash + Copy + Clone + Debug + Display + Serialize + DeserializeOwned;
+    type SecretKey: Debug + Serialize + DeserializeOwned;
+    type Signature: Ord + Hash + Clone + Debug + Serialize + DeserializeOwned;
+    type Error: std::error::Error;
+
+    fn public_key(secret_key: &Self::SecretKey) -> Self::PublicKey;
+    fn sign(secret_key: &Self::SecretKey, msg: &[u8]) -> Self::Signature;
+    fn verify(
+        public_key: &Self::PublicKey,
+        msg: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<(), Self::Error>;
+}
+
+/// The [`Scheme`] this build's `ed25519`/`blsttc`/`bad_crypto` feature flag
+/// selects; see the module doc comment.
+#[cfg(feature = "bad_crypto")]
+pub type DefaultScheme = crate::bad_crypto::BadCryptoScheme;
+#[cfg(feature = "blsttc")]
+pub type DefaultScheme = crate::blsttc::BlsttcScheme;
+#[cfg(feature = "ed25519")]
+pub type DefaultScheme = crate::ed25519::Ed25519Scheme;
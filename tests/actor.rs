@@ -0,0 +1,240 @@
+//! Sign/verify round-trips for the `actor` module's building blocks. These
+//! are plain cryptographic units with no dependency on `State`, so each gets
+//! its own focused test here rather than going through a `Net`.
+
+use std::collections::BTreeSet;
+
+use brb_membership::actor::{bls, ed25519, multi};
+use brb_membership::{Actor as _, SigningActor as _};
+use signature::{Signer, Verifier};
+
+#[test]
+fn multi_sig_round_trips_both_variants_and_rejects_cross_variant() {
+    let ed_signing = multi::SigningActor::Ed25519(ed25519::SigningActor::default());
+    let bls_signing = multi::SigningActor::Bls(bls::SigningActor::default());
+
+    let msg = b"reconfigure";
+    let ed_sig = ed_signing.try_sign(msg).unwrap();
+    let bls_sig = bls_signing.try_sign(msg).unwrap();
+
+    let ed_actor = ed_signing.actor();
+    let bls_actor = bls_signing.actor();
+
+    ed_actor.verify(msg, &ed_sig).expect("ed25519 sig should verify under its own actor");
+    bls_actor.verify(msg, &bls_sig).expect("bls sig should verify under its own actor");
+
+    // An ed25519 actor can never be fooled into accepting a bls signature,
+    // or vice versa, even though both live in the same `multi::Sig` enum.
+    assert!(ed_actor.verify(msg, &bls_sig).is_err());
+    assert!(bls_actor.verify(msg, &ed_sig).is_err());
+}
+
+#[test]
+fn threshold_group_combines_t_plus_1_shares_but_not_t() {
+    use brb_membership::blsttc::{aggregate, ThresholdGroup};
+    use rand::rngs::OsRng;
+
+    // threshold = 1 of n = 4, so 2 shares are required to reconstruct.
+    let group = ThresholdGroup::deal(1, 4, OsRng);
+    let public_key_set = group.public_key_set();
+    let msg = b"reconfigure";
+
+    let share0 = group.secret_key_share(0).unwrap().sign(msg);
+    let share1 = group.secret_key_share(1).unwrap().sign(msg);
+
+    // A single share (t shares) can't reconstruct the group signature.
+    assert!(aggregate(&public_key_set, msg, &[(0, share0.share().clone())]).is_err());
+
+    // t + 1 valid shares combine into a signature that verifies under the
+    // group's public key.
+    let combined = aggregate(
+        &public_key_set,
+        msg,
+        &[(0, share0.share().clone()), (1, share1.share().clone())],
+    )
+    .expect("threshold + 1 valid shares should combine");
+    assert!(public_key_set.public_key().verify(&combined, msg));
+
+    // A share signed over a different message is rejected rather than
+    // silently folded into a bogus combined signature.
+    let mismatched_share = group.secret_key_share(2).unwrap().sign(b"a different message");
+    assert!(aggregate(
+        &public_key_set,
+        msg,
+        &[(0, share0.share().clone()), (2, mismatched_share.share().clone())],
+    )
+    .is_err());
+}
+
+#[test]
+fn frost_aggregates_a_valid_signature_from_threshold_plus_one_signers() {
+    use brb_membership::ed25519::frost;
+    use rand::rngs::OsRng;
+
+    // threshold = 2 of n = 4, so 3 signers must cooperate.
+    let ids: BTreeSet<frost::Identifier> = (0..4).collect();
+    let key_shares = frost::keygen(2, &ids, OsRng);
+    let group_public_key = key_shares[&0].group_public_key;
+
+    let msg = b"reconfigure";
+    let signer_ids = [0u16, 1, 3];
+
+    let mut nonces_by_id = std::collections::BTreeMap::new();
+    let mut commitments = BTreeSet::new();
+    for &id in &signer_ids {
+        let (nonces, commitment) = frost::commit(id, OsRng);
+        nonces_by_id.insert(id, nonces);
+        commitments.insert(commitment);
+    }
+
+    let mut shares = Vec::new();
+    for &id in &signer_ids {
+        let nonces = nonces_by_id.remove(&id).unwrap();
+        shares.push(frost::sign(&key_shares[&id], nonces, msg, 2, &commitments).unwrap());
+    }
+
+    let signature = frost::aggregate(msg, 2, &commitments, &shares).unwrap();
+    group_public_key
+        .verify(msg, &signature)
+        .expect("combining threshold + 1 shares should produce a verifiable signature");
+
+    // A signature combined from a different quorum (sharing signers 0 and 3
+    // with the first) over the same message must still verify under the
+    // same group key.
+    let other_signer_ids = [0u16, 2, 3];
+    let mut other_nonces_by_id = std::collections::BTreeMap::new();
+    let mut other_commitments = BTreeSet::new();
+    for &id in &other_signer_ids {
+        let (nonces, commitment) = frost::commit(id, OsRng);
+        other_nonces_by_id.insert(id, nonces);
+        other_commitments.insert(commitment);
+    }
+    let mut other_shares = Vec::new();
+    for &id in &other_signer_ids {
+        let nonces = other_nonces_by_id.remove(&id).unwrap();
+        other_shares.push(frost::sign(&key_shares[&id], nonces, msg, 2, &other_commitments).unwrap());
+    }
+    let other_signature = frost::aggregate(msg, 2, &other_commitments, &other_shares).unwrap();
+    group_public_key
+        .verify(msg, &other_signature)
+        .expect("any threshold + 1 quorum should produce a verifiable signature");
+}
+
+#[test]
+fn frost_rejects_nonce_reuse_across_two_sign_calls() {
+    use brb_membership::ed25519::frost;
+    use rand::rngs::OsRng;
+
+    let ids: BTreeSet<frost::Identifier> = (0..4).collect();
+    let key_shares = frost::keygen(2, &ids, OsRng);
+
+    let signer_ids = [0u16, 1, 3];
+    let mut nonces_by_id = std::collections::BTreeMap::new();
+    let mut commitments = BTreeSet::new();
+    for &id in &signer_ids {
+        let (nonces, commitment) = frost::commit(id, OsRng);
+        nonces_by_id.insert(id, nonces);
+        commitments.insert(commitment);
+    }
+
+    // Signing once consumes the nonce pair; a second `sign` call can't be
+    // handed the same `SigningNonces` value again -- the compiler, not a
+    // runtime check, is what rejects the reuse.
+    let nonces = nonces_by_id.remove(&0).unwrap();
+    frost::sign(&key_shares[&0], nonces, b"first message", 2, &commitments).unwrap();
+
+    // A stale commitment that no longer matches any nonces this signer still
+    // holds is rejected rather than silently accepted.
+    let (stale_nonces, _) = frost::commit(0, OsRng);
+    let err = frost::sign(&key_shares[&0], stale_nonces, b"second message", 2, &commitments).unwrap_err();
+    assert!(matches!(err, frost::Error::NonceReused));
+}
+
+#[test]
+fn purpose_scoped_signature_rejects_replay_under_a_different_purpose() {
+    use brb_membership::actor::purpose::{Join, Leave, Signed};
+
+    let signing_actor = ed25519::SigningActor::default();
+    let value = b"alice".to_vec();
+
+    let signed = Signed::<_, Join, _, _>::sign(&signing_actor, value.clone()).unwrap();
+    Signed::<_, Join, _, _>::verify(signed.actor, value.clone(), signed.sig.clone())
+        .expect("a signature made for Join should verify as Join");
+
+    // The exact same signature, over the exact same bytes, must not verify
+    // under a different Purpose: Leave mixes in a different TAG.
+    assert!(Signed::<_, Leave, _, _>::verify(signed.actor, value, signed.sig).is_err());
+}
+
+#[test]
+fn blinded_identity_round_trips_and_stays_unlinkable_to_the_long_term_key() {
+    use brb_membership::actor::ed25519::blind;
+
+    let signing_actor = ed25519::SigningActor::default();
+    let actor = signing_actor.actor();
+    let seed = b"group-42";
+
+    let blinded_signing_actor = signing_actor.blind(seed);
+    let blinded_actor = actor.blind(seed);
+    let msg = b"reconfigure";
+    let sig = blinded_signing_actor.try_sign(msg).unwrap();
+
+    blinded_actor
+        .verify(msg, &sig)
+        .expect("a blinded signature should verify under the matching blinded identity");
+
+    // `unblind` recovers the exact same blinded keypair from the long-term
+    // signing actor given the same seed, not merely an equivalent one.
+    let recovered = blind::unblind(&signing_actor, seed);
+    let recovered_sig = recovered.try_sign(msg).unwrap();
+    blinded_actor
+        .verify(msg, &recovered_sig)
+        .expect("unblind should recover the same blinded keypair `blind` derives directly");
+
+    // The long-term actor can't verify a signature made under its own
+    // blinded identity: `BlindedSigningActor` signs with a shifted scalar, so
+    // the result doesn't satisfy the unblinded Schnorr equation either.
+    assert!(actor.verify(msg, &sig).is_err());
+}
+
+#[test]
+fn streamed_signature_is_interchangeable_with_a_one_shot_signature_over_the_same_bytes() {
+    use brb_membership::actor::ed25519::multipart::{MultipartSigner, MultipartVerifier};
+    use brb_membership::actor::{StreamingSigner, StreamingVerifier};
+
+    let signing_actor = ed25519::SigningActor::default();
+    let actor = signing_actor.actor();
+    let chunks: [&[u8]; 3] = [b"re", b"config", b"ure"];
+    let whole: Vec<u8> = chunks.concat();
+
+    let mut streaming_signer = MultipartSigner::new(&signing_actor);
+    for chunk in &chunks {
+        streaming_signer.update(chunk);
+    }
+    let streamed_sig = streaming_signer.finalize().unwrap();
+
+    // A streamed signature verifies both through the streaming verifier...
+    let mut streaming_verifier = MultipartVerifier::new(&actor);
+    for chunk in &chunks {
+        streaming_verifier.update(chunk);
+    }
+    streaming_verifier
+        .finalize_and_verify(&streamed_sig)
+        .expect("a streamed signature should verify through the streaming verifier");
+
+    // ...and through the plain one-shot `Actor::verify` over the
+    // concatenated bytes, since both paths share the same prehash/CONTEXT.
+    actor
+        .verify(&whole, &streamed_sig)
+        .expect("a streamed signature should verify one-shot over the concatenated bytes");
+
+    // And a one-shot signature verifies through the streaming verifier too.
+    let one_shot_sig = signing_actor.try_sign(&whole).unwrap();
+    let mut streaming_verifier = MultipartVerifier::new(&actor);
+    for chunk in &chunks {
+        streaming_verifier.update(chunk);
+    }
+    streaming_verifier
+        .finalize_and_verify(&one_shot_sig)
+        .expect("a one-shot signature should verify through the streaming verifier");
+}
@@ -5,15 +5,70 @@ use std::iter;
 
 //use brb_membership::{Error, Generation, Reconfig, State, VoteMsg};
 use brb_membership::{
-    Ballot, Error, Generation, PublicKey, Reconfig, SignedVote, State, Vote, VoteMsg,
+    Ballot, Error, Fault, Generation, PublicKey, Reconfig, SignedVote, State, Vote, VoteMsg,
 };
 use rand::prelude::{IteratorRandom, StdRng};
 use rand::Rng;
 
+/// Which procs a `Packet` is actually allowed to reach, independent of
+/// `vote_msg.dest` (the address the real protocol message itself carries).
+/// Mirrors hbbft's messaging `Target`, retrofitted onto this harness's
+/// already-addressed packets as a delivery filter rather than a fan-out: a
+/// whitelist of specific nodes, or everyone except a blacklist. This is the
+/// foundation partition/censorship testing builds on -- no vote payload is
+/// ever cloned out to a recipient `Target` excludes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// Deliverable only if `vote_msg.dest` is one of these.
+    Nodes(BTreeSet<PublicKey>),
+    /// Deliverable unless `vote_msg.dest` is one of these.
+    /// `AllExcept(BTreeSet::new())` is plain broadcast.
+    AllExcept(BTreeSet<PublicKey>),
+}
+
+impl Default for Target {
+    /// Plain broadcast: nothing is excluded.
+    fn default() -> Self {
+        Target::AllExcept(Default::default())
+    }
+}
+
+impl Target {
+    fn allows(&self, dest: &PublicKey) -> bool {
+        match self {
+            Target::Nodes(nodes) => nodes.contains(dest),
+            Target::AllExcept(excluded) => !excluded.contains(dest),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Packet {
     pub source: PublicKey,
     pub vote_msg: VoteMsg,
+    pub target: Target,
+}
+
+impl Packet {
+    /// A plain broadcast packet: deliverable to whatever `vote_msg.dest`
+    /// already says, same as before `Target` existed.
+    pub fn new(source: PublicKey, vote_msg: VoteMsg) -> Self {
+        Self {
+            source,
+            vote_msg,
+            target: Target::default(),
+        }
+    }
+
+    /// A packet restricted to `target`, e.g. to drive partition or
+    /// targeted-censorship scenarios.
+    pub fn targeted(source: PublicKey, vote_msg: VoteMsg, target: Target) -> Self {
+        Self {
+            source,
+            vote_msg,
+            target,
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -23,6 +78,186 @@ pub struct Net {
     pub members_at_gen: BTreeMap<Generation, BTreeSet<PublicKey>>,
     pub packets: BTreeMap<PublicKey, VecDeque<Packet>>,
     pub delivered_packets: Vec<Packet>,
+    pub faults: Vec<Fault>,
+    /// Every tamper `schedule` applied along the way, in order, so
+    /// `generate_msc` can annotate the trace with what diverged it from a
+    /// plain FIFO drain.
+    pub schedule_log: Vec<ScheduleEvent>,
+    /// Which source `crank` drained from at each step, in order. Recording
+    /// this (rather than re-deriving the interleaving from the RNG seed
+    /// that produced it) is what lets a failing case be replayed verbatim
+    /// via `Net::replay_schedule`, sidestepping quickcheck's shrinker --
+    /// which shrinks `n`/`seed`/instruction counts fine, but can't usefully
+    /// shrink *which packet was delivered when*.
+    pub crank_schedule: Vec<PublicKey>,
+    /// Groups of procs that currently can't exchange packets, installed by
+    /// `partition` and cleared by `heal`. Unlike `SchedulePolicy::partitions`
+    /// (which only ever applies within a single `schedule` run), this is a
+    /// standing connectivity matrix every delivery path -- `deliver`,
+    /// `crank`, `drain_queued_packets` -- honors until healed.
+    pub partitions: BTreeSet<BTreeSet<PublicKey>>,
+}
+
+/// A step `Net::schedule` took that a plain `drain_queued_packets` walk
+/// never would.
+#[derive(Debug, Clone)]
+pub enum ScheduleEvent {
+    /// `packet` was held back this step: its source and destination fall in
+    /// different halves of a [`SchedulePolicy::partitions`] grouping.
+    HeldByPartition(Packet),
+    /// `packet` was delivered out of its source queue's FIFO order.
+    Reordered(Packet),
+    /// `packet` was delivered once, then re-enqueued to simulate the
+    /// network duplicating it.
+    Duplicated(Packet),
+}
+
+/// A deterministic adversarial delivery policy for [`Net::schedule`]:
+/// instead of `drain_queued_packets`' near-FIFO walk of `packets`'
+/// `BTreeMap` key order, delivery is driven under network partitions,
+/// packet duplication, and bounded out-of-order delivery, the message
+/// schedules that actually expose consensus bugs.
+#[derive(Debug, Clone, Default)]
+pub struct SchedulePolicy {
+    /// Groups of procs that can't exchange packets with each other: a
+    /// packet whose source and destination fall in different groups is held
+    /// rather than delivered, until `heal_partitions_after` elapses.
+    pub partitions: BTreeSet<BTreeSet<PublicKey>>,
+    /// Once `schedule` has taken this many steps, `partitions` is cleared
+    /// and the network is fully connected again, so post-heal liveness can
+    /// be asserted. `None` never heals.
+    pub heal_partitions_after: Option<usize>,
+    /// Probability (0.0..=1.0) that a delivered packet is re-enqueued once
+    /// more to its source's queue, simulating duplication.
+    pub duplication_rate: f64,
+    /// Probability (0.0..=1.0) that, instead of a source queue's front
+    /// packet, a uniformly random queued packet from that source is
+    /// delivered out of order.
+    pub reorder_rate: f64,
+}
+
+impl SchedulePolicy {
+    fn are_partitioned(&self, a: &PublicKey, b: &PublicKey) -> bool {
+        self.partitions
+            .iter()
+            .any(|group| group.contains(a) != group.contains(b))
+    }
+}
+
+/// A crank between packet deliveries in [`Net::drain_queued_packets_with`],
+/// hbbft-net-simulator style: given a mutable handle to the pending
+/// `packets` queues and the test's own `StdRng`, an `Adversary` may reorder,
+/// duplicate, or otherwise tamper with in-flight `Packet`s before the next
+/// one is delivered, all deterministically from the seed that's already
+/// threaded through every `prop_*` test.
+pub trait Adversary: std::fmt::Debug {
+    /// Tampers with the whole pending `packets` map between crank steps of
+    /// `drain_queued_packets_with`. Default: no tampering.
+    fn tamper(&mut self, _packets: &mut BTreeMap<PublicKey, VecDeque<Packet>>, _rng: &mut StdRng) {}
+
+    /// Called on a single packet as `Net::deliver_next_with` is about to
+    /// deliver it; returns the packet(s) actually delivered in its place.
+    /// An empty `Vec` drops it, a `Vec` of one forged packet substitutes it,
+    /// and a `Vec` of more than one duplicates it -- dropping and forging
+    /// are just different answers to "what replaces this?". Default:
+    /// delivered unchanged.
+    fn on_packet(&mut self, _net: &mut Net, packet: &Packet) -> Vec<Packet> {
+        vec![packet.clone()]
+    }
+
+    /// Chooses, by index into `queued`, which pending packet
+    /// `Net::deliver_next_with` cranks next. Default: the first, i.e.
+    /// honest FIFO order.
+    fn pick_next(&mut self, _queued: &[Packet]) -> usize {
+        0
+    }
+}
+
+/// Honest scheduling, no tampering, no mutation: `drain_queued_packets_with`
+/// and `deliver_next_with` both behave exactly like their `Adversary`-less
+/// counterparts.
+#[derive(Debug, Default)]
+pub struct SilentAdversary;
+
+impl Adversary for SilentAdversary {}
+
+/// Always delivers the lowest-key source's packets first. `packets` is
+/// already a `BTreeMap` keyed by source, so the default `pick_next` (which
+/// just takes `queued`'s first entry, itself built by walking `packets` in
+/// key order) already implements this; this adversary exists to make that a
+/// deliberate, named policy rather than an implementation detail a test
+/// happens to rely on.
+#[derive(Debug, Default)]
+pub struct NodeOrderAdversary;
+
+impl Adversary for NodeOrderAdversary {}
+
+/// Randomly permutes in-flight packets: each crank, a random source queue
+/// with more than one packet has two of its entries swapped, and with some
+/// probability a delivered-next packet is duplicated back onto its queue.
+#[derive(Debug, Default)]
+pub struct ReorderingAdversary;
+
+impl Adversary for ReorderingAdversary {
+    fn tamper(&mut self, packets: &mut BTreeMap<PublicKey, VecDeque<Packet>>, rng: &mut StdRng) {
+        let reorderable: Vec<_> = packets
+            .iter()
+            .filter(|(_, queue)| queue.len() > 1)
+            .map(|(source, _)| *source)
+            .collect();
+
+        if let Some(source) = reorderable.iter().choose(rng) {
+            let queue = packets.get_mut(source).expect("source came from `packets`");
+            let len = queue.len();
+            queue.swap(rng.gen::<usize>() % len, rng.gen::<usize>() % len);
+        }
+
+        if rng.gen::<bool>() {
+            if let Some((_, queue)) = packets
+                .iter_mut()
+                .filter(|(_, q)| !q.is_empty())
+                .choose(rng)
+            {
+                let duplicate = queue[0].clone();
+                queue.push_back(duplicate);
+            }
+        }
+    }
+}
+
+/// Wraps `Net::gen_faulty_packet`/the faulty-vote generator behind the
+/// `Adversary` interface: every packet due for delivery is, with even odds,
+/// substituted for a freshly forged one signed by a faulty actor, or
+/// dropped outright. This is the coin flip `prop_bft_consensus` used to run
+/// inline before byzantine behavior was made pluggable.
+#[derive(Debug)]
+pub struct RandomAdversary {
+    recursion_limit: u8,
+    faulty: BTreeSet<PublicKey>,
+    rng: StdRng,
+}
+
+impl RandomAdversary {
+    pub fn new(recursion_limit: u8, faulty: BTreeSet<PublicKey>, rng: StdRng) -> Self {
+        Self {
+            recursion_limit,
+            faulty,
+            rng,
+        }
+    }
+}
+
+impl Adversary for RandomAdversary {
+    fn on_packet(&mut self, net: &mut Net, packet: &Packet) -> Vec<Packet> {
+        if self.faulty.is_empty() {
+            return vec![packet.clone()];
+        }
+
+        match self.rng.gen::<bool>() {
+            true => vec![net.gen_faulty_packet(self.recursion_limit, &self.faulty, &mut self.rng)],
+            false => vec![], // drop it instead of delivering
+        }
+    }
 }
 
 impl Net {
@@ -56,10 +291,10 @@ impl Net {
         rng: &mut StdRng,
     ) -> Ballot {
         match rng.gen() || recursion == 0 {
-            true => Ballot::Propose(match rng.gen() {
+            true => Ballot::Propose(BTreeSet::from_iter([match rng.gen() {
                 true => Reconfig::Join(self.gen_public_key(rng)),
                 false => Reconfig::Leave(self.gen_public_key(rng)),
-            }),
+            }])),
             false => {
                 let n_votes = rng.gen::<usize>() % self.procs.len().pow(2);
                 let random_votes = BTreeSet::from_iter(
@@ -98,6 +333,43 @@ impl Net {
         signed_vote
     }
 
+    /// Signs a `Propose(reconfig)` ballot as `self.procs[voter_idx]`,
+    /// entirely bypassing `State::propose`'s own bookkeeping (its `vote_seq`
+    /// counter, its rejection of a second incompatible vote). This models a
+    /// Byzantine member who holds its own `SecretKey` and is willing to craft
+    /// any well-signed vote it likes, including one that equivocates against
+    /// a vote it already cast: call this twice with two different reconfigs
+    /// and the same `vote_seq` to get a genuinely conflicting pair (neither
+    /// `SignedVote::supersedes` the other), then route them to disjoint
+    /// halves of the network.
+    pub fn craft_vote(
+        &self,
+        voter_idx: usize,
+        reconfig: Reconfig,
+        vote_seq: u64,
+    ) -> Result<SignedVote, Error> {
+        let voter = &self.procs[voter_idx % self.procs.len()];
+        let gen = voter.gen + 1;
+        let ballot = Ballot::Propose(BTreeSet::from_iter([reconfig]));
+        let blob_bytes = bincode::serialize(&(&ballot, &gen))?;
+
+        Ok(SignedVote {
+            voter: voter.public_key(),
+            sig: voter.secret_key.sign(&blob_bytes),
+            ballot,
+            gen,
+            vote_seq,
+            #[cfg(feature = "blsttc")]
+            cert_share: None,
+            #[cfg(feature = "blsttc")]
+            checkpoint_share: None,
+            #[cfg(feature = "blsttc")]
+            coin_share: None,
+            #[cfg(feature = "blsttc")]
+            key_succession_share: None,
+        })
+    }
+
     /// Generate a faulty random packet
     pub fn gen_faulty_packet(
         &self,
@@ -105,13 +377,13 @@ impl Net {
         faulty: &BTreeSet<PublicKey>,
         rng: &mut StdRng,
     ) -> Packet {
-        Packet {
-            source: *faulty.iter().choose(rng).unwrap(),
-            vote_msg: VoteMsg {
+        Packet::new(
+            *faulty.iter().choose(rng).unwrap(),
+            VoteMsg {
                 vote: self.gen_faulty_vote(recursion, faulty, rng),
                 dest: self.gen_public_key(rng),
             },
-        }
+        )
     }
 
     pub fn genesis(&self) -> Result<PublicKey, Error> {
@@ -131,10 +403,29 @@ impl Net {
             _ => return Ok(()), // nothing to do
         };
         self.purge_empty_queues();
+        self.deliver(packet)
+    }
 
+    /// The common tail of delivery, shared by `deliver_packet_from_source`
+    /// and `schedule`: apply `packet` to its destination and check the
+    /// `members_at_gen` safety oracle, regardless of how `packet` was picked
+    /// off its queue.
+    fn deliver(&mut self, packet: Packet) -> Result<(), Error> {
         let dest = packet.vote_msg.dest;
         // println!("delivering {:?}->{:?} {:?}", packet.source, dest, packet);
 
+        if !packet.target.allows(&dest) {
+            // `dest` is outside this packet's Target: silently censored,
+            // same as if the destination proc didn't exist.
+            return Ok(());
+        }
+
+        if self.are_partitioned(&packet.source, &dest) {
+            // `source` and `dest` fall in different halves of a standing
+            // `partition`: dropped silently, same as a `Target` exclusion.
+            return Ok(());
+        }
+
         self.delivered_packets.push(packet.clone());
 
         let dest_proc_opt = self.procs.iter_mut().find(|p| p.public_key() == dest);
@@ -153,12 +444,14 @@ impl Net {
         let resp = dest_proc.handle_signed_vote(vote);
         // println!("[NET] resp: {:?}", resp);
         match resp {
-            Ok(vote_msgs) => {
+            Ok((vote_msgs, faults)) => {
                 let dest_actor = dest_proc.public_key();
-                self.enqueue_packets(vote_msgs.into_iter().map(|vote_msg| Packet {
-                    source: dest_actor,
-                    vote_msg,
-                }));
+                self.enqueue_packets(
+                    vote_msgs
+                        .into_iter()
+                        .map(|vote_msg| Packet::new(dest_actor, vote_msg)),
+                );
+                self.faults.extend(faults);
             }
             Err(Error::NonMember {
                 public_key: voter,
@@ -168,7 +461,7 @@ impl Net {
                 assert!(
                     !dest_members.contains(&voter),
                     "{:?} should not be in {:?}",
-                    source,
+                    packet.source,
                     dest_members
                 );
             }
@@ -209,14 +502,162 @@ impl Net {
         }
     }
 
+    /// A single step of delivery, hbbft `VirtualNet::crank` style: pops and
+    /// delivers exactly one queued packet -- the same FIFO-by-source order
+    /// `drain_queued_packets` always walked -- and returns the `(dest,
+    /// vote_msg)` it just processed, or `None` once nothing is queued.
+    /// `drain_queued_packets` is just this called in a loop; the point of
+    /// exposing it is so a caller can single-step delivery and observe, or
+    /// later replay via `crank_schedule`, one message at a time.
+    pub fn crank(&mut self) -> Result<Option<(PublicKey, VoteMsg)>, Error> {
+        self.purge_empty_queues();
+        let source = match self.packets.keys().next().copied() {
+            Some(source) => source,
+            None => return Ok(None),
+        };
+        let packet = self
+            .packets
+            .get_mut(&source)
+            .and_then(VecDeque::pop_front)
+            .expect("source came from `packets`");
+        self.purge_empty_queues();
+
+        let vote_msg = packet.vote_msg.clone();
+        self.crank_schedule.push(source);
+        self.deliver(packet)?;
+        Ok(Some((vote_msg.dest, vote_msg)))
+    }
+
     pub fn drain_queued_packets(&mut self) -> Result<(), Error> {
-        while let Some(source) = self.packets.keys().next().cloned() {
-            self.deliver_packet_from_source(source)?;
+        while self.crank()?.is_some() {}
+        Ok(())
+    }
+
+    /// Like `drain_queued_packets`, but gives `adversary` a crank between
+    /// every delivery: a chance to tamper with `self.packets` (reorder,
+    /// duplicate, drop) before the next packet is picked off. With a
+    /// `SilentAdversary` this delivers in the same order as
+    /// `drain_queued_packets`, but unlike `crank` it does not record
+    /// `crank_schedule`.
+    pub fn drain_queued_packets_with(
+        &mut self,
+        adversary: &mut dyn Adversary,
+        rng: &mut StdRng,
+    ) -> Result<(), Error> {
+        loop {
             self.purge_empty_queues();
+            adversary.tamper(&mut self.packets, rng);
+            self.purge_empty_queues();
+
+            let source = match self.packets.keys().next().cloned() {
+                Some(source) => source,
+                None => return Ok(()),
+            };
+            self.deliver_packet_from_source(source)?;
+        }
+    }
+
+    /// Pops whichever queued packet `adversary` picks via `pick_next`, lets
+    /// it mutate/drop/duplicate that packet via `on_packet`, and delivers
+    /// whatever comes back. A no-op if nothing is queued.
+    ///
+    /// The single-packet-granularity counterpart to
+    /// `drain_queued_packets_with`'s per-crank `tamper`, for callers like
+    /// `prop_bft_consensus` that drive delivery one packet at a time
+    /// interleaved with other actions, rather than draining the whole
+    /// queue in one go.
+    pub fn deliver_next_with(&mut self, adversary: &mut dyn Adversary) -> Result<(), Error> {
+        let queued: Vec<Packet> = self.packets.values().flatten().cloned().collect();
+        if queued.is_empty() {
+            return Ok(());
+        }
+
+        let idx = adversary.pick_next(&queued).min(queued.len() - 1);
+        let chosen = queued[idx].clone();
+
+        if let Some(source_queue) = self.packets.get_mut(&chosen.source) {
+            if let Some(pos) = source_queue.iter().position(|p| *p == chosen) {
+                source_queue.remove(pos);
+            }
+        }
+        self.purge_empty_queues();
+
+        for packet in adversary.on_packet(self, &chosen) {
+            self.deliver(packet)?;
         }
         Ok(())
     }
 
+    /// Drives delivery for up to `max_steps` steps under `policy`'s
+    /// adversarial model rather than `drain_queued_packets`' plain FIFO
+    /// walk: a packet held back by a partition is skipped this step instead
+    /// of delivered, a delivered packet may be re-enqueued once more to
+    /// simulate duplication, and a source queue's packet may be popped out
+    /// of its FIFO order. The `members_at_gen` agreement assertion that
+    /// `deliver` already checks after every delivery is the safety oracle
+    /// here too — a violation surfaces as an `Err` from the step that
+    /// caused it, rather than only once every packet has drained.
+    ///
+    /// Returns whether the run converged: every partition healed (per
+    /// `policy.heal_partitions_after`) and every queue drained within
+    /// `max_steps`.
+    pub fn schedule(
+        &mut self,
+        mut policy: SchedulePolicy,
+        rng: &mut StdRng,
+        max_steps: usize,
+    ) -> Result<bool, Error> {
+        for step in 0..max_steps {
+            if policy.heal_partitions_after == Some(step) {
+                policy.partitions.clear();
+            }
+
+            self.purge_empty_queues();
+
+            let sources = Vec::from_iter(self.packets.keys().copied());
+            if sources.is_empty() {
+                if policy.partitions.is_empty() {
+                    return Ok(true);
+                }
+                continue;
+            }
+
+            let source = *sources.iter().choose(rng).unwrap();
+            let queue_len = self.packets[&source].len();
+
+            let reorder = rng.gen::<f64>() < policy.reorder_rate && queue_len > 1;
+            let idx = if reorder {
+                rng.gen::<usize>() % queue_len
+            } else {
+                0
+            };
+            let packet = self.packets[&source][idx].clone();
+
+            if policy.are_partitioned(&packet.source, &packet.vote_msg.dest) {
+                self.schedule_log
+                    .push(ScheduleEvent::HeldByPartition(packet));
+                continue;
+            }
+
+            self.packets.get_mut(&source).unwrap().remove(idx);
+            if reorder {
+                self.schedule_log
+                    .push(ScheduleEvent::Reordered(packet.clone()));
+            }
+
+            self.deliver(packet.clone())?;
+
+            if rng.gen::<f64>() < policy.duplication_rate {
+                self.schedule_log
+                    .push(ScheduleEvent::Duplicated(packet.clone()));
+                self.enqueue_packets(std::iter::once(packet));
+            }
+        }
+
+        self.purge_empty_queues();
+        Ok(self.packets.is_empty() && policy.partitions.is_empty())
+    }
+
     pub fn purge_empty_queues(&mut self) {
         self.packets = core::mem::take(&mut self.packets)
             .into_iter()
@@ -230,17 +671,38 @@ impl Net {
         }
     }
 
+    /// Installs `groups` as a standing connectivity matrix: from now on,
+    /// `deliver` silently drops any packet whose source and destination
+    /// fall in different groups, until `heal` is called. A proc absent from
+    /// every group can only exchange packets with other procs absent from
+    /// every group.
+    pub fn partition(&mut self, groups: Vec<BTreeSet<PublicKey>>) {
+        self.partitions = BTreeSet::from_iter(groups);
+    }
+
+    /// Restores full connectivity: every packet is deliverable again
+    /// regardless of any previously-installed `partition`.
+    pub fn heal(&mut self) {
+        self.partitions.clear();
+    }
+
+    fn are_partitioned(&self, a: &PublicKey, b: &PublicKey) -> bool {
+        self.partitions
+            .iter()
+            .any(|group| group.contains(a) != group.contains(b))
+    }
+
     pub fn enqueue_anti_entropy(&mut self, i: usize, j: usize) {
         let i_gen = self.procs[i].gen;
         let i_actor = self.procs[i].public_key();
         let j_actor = self.procs[j].public_key();
 
-        self.enqueue_packets(self.procs[j].anti_entropy(i_gen, i_actor).into_iter().map(
-            |vote_msg| Packet {
-                source: j_actor,
-                vote_msg,
-            },
-        ));
+        self.enqueue_packets(
+            self.procs[j]
+                .anti_entropy(i_gen, i_actor)
+                .into_iter()
+                .map(|vote_msg| Packet::new(j_actor, vote_msg)),
+        );
     }
 
     pub fn generate_msc(&self, name: &str) -> Result<(), Error> {
@@ -269,6 +731,21 @@ msc {\n
             ));
         }
 
+        // `schedule`'s tampering, if any, annotated as dashed arcs so a
+        // diverging trace (a held-back, duplicated, or reordered packet)
+        // explains itself instead of looking like a plain FIFO drain.
+        for event in self.schedule_log.iter() {
+            let (label, packet) = match event {
+                ScheduleEvent::HeldByPartition(packet) => ("HELD(partition)", packet),
+                ScheduleEvent::Reordered(packet) => ("REORDERED", packet),
+                ScheduleEvent::Duplicated(packet) => ("DUPLICATED", packet),
+            };
+            msc.push_str(&format!(
+                "{} -->> {} [ label=\"{} {:?}\"];\n",
+                packet.source, packet.vote_msg.dest, label, packet.vote_msg.vote
+            ));
+        }
+
         msc.push_str("}\n");
 
         // Replace process identifiers with friendlier numbers
@@ -282,4 +759,27 @@ msc {\n
         msc_file.write_all(msc.as_bytes())?;
         Ok(())
     }
+
+    /// Deterministically reruns a saved [`crate::Trace`] through
+    /// `crate::run_interpreter`, regenerating its `.msc` along the way, so a
+    /// quickcheck counterexample can be replayed without re-deriving the
+    /// random seed from the shrinker.
+    pub fn replay(trace: &crate::Trace) -> eyre::Result<quickcheck::TestResult> {
+        crate::run_interpreter(trace)
+    }
+
+    /// Cranks `schedule` -- a prior run's `crank_schedule` -- against
+    /// `self` verbatim, one recorded source per step, instead of
+    /// re-deriving the interleaving from whatever random choices originally
+    /// produced it. This only replays *which packet was delivered when*;
+    /// the caller must first redrive whatever proposals/anti-entropy
+    /// enqueued those packets in the original scenario (same seed, same
+    /// instructions) before feeding `self` to this.
+    pub fn replay_schedule(&mut self, schedule: &[PublicKey]) -> Result<(), Error> {
+        for &source in schedule {
+            self.deliver_packet_from_source(source)?;
+            self.crank_schedule.push(source);
+        }
+        Ok(())
+    }
 }